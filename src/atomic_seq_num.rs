@@ -0,0 +1,121 @@
+use crate::seq_num::SeqNum;
+use core::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize, Ordering};
+
+/// Helper trait, parallel to [`SeqNum`], that pairs an unsigned integer type with the
+/// lock-free atomic type that can store it.
+///
+/// Implemented for every `SeqNum` that has a native atomic counterpart in
+/// `std::sync::atomic` (all unsigned integers up to `u64`/`usize`; `u128` has none and
+/// therefore does not implement this trait).
+pub trait AtomicSeqNum: SeqNum {
+    /// The atomic type that can hold and update `Self` without a lock.
+    type Atomic;
+
+    /// Creates a new atomic cell holding `val`.
+    fn new_atomic(val: Self) -> Self::Atomic;
+    /// Loads the current value with the given memory ordering.
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+    /// Calls `compare_exchange_weak` of the atomic type.
+    fn compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+}
+impl AtomicSeqNum for u8 {
+    type Atomic = AtomicU8;
+
+    fn new_atomic(val: Self) -> Self::Atomic {
+        AtomicU8::new(val)
+    }
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+        atomic.load(order)
+    }
+    fn compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self> {
+        atomic.compare_exchange_weak(current, new, success, failure)
+    }
+}
+impl AtomicSeqNum for u16 {
+    type Atomic = AtomicU16;
+
+    fn new_atomic(val: Self) -> Self::Atomic {
+        AtomicU16::new(val)
+    }
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+        atomic.load(order)
+    }
+    fn compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self> {
+        atomic.compare_exchange_weak(current, new, success, failure)
+    }
+}
+impl AtomicSeqNum for u32 {
+    type Atomic = AtomicU32;
+
+    fn new_atomic(val: Self) -> Self::Atomic {
+        AtomicU32::new(val)
+    }
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+        atomic.load(order)
+    }
+    fn compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self> {
+        atomic.compare_exchange_weak(current, new, success, failure)
+    }
+}
+impl AtomicSeqNum for u64 {
+    type Atomic = AtomicU64;
+
+    fn new_atomic(val: Self) -> Self::Atomic {
+        AtomicU64::new(val)
+    }
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+        atomic.load(order)
+    }
+    fn compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self> {
+        atomic.compare_exchange_weak(current, new, success, failure)
+    }
+}
+impl AtomicSeqNum for usize {
+    type Atomic = AtomicUsize;
+
+    fn new_atomic(val: Self) -> Self::Atomic {
+        AtomicUsize::new(val)
+    }
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+        atomic.load(order)
+    }
+    fn compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self> {
+        atomic.compare_exchange_weak(current, new, success, failure)
+    }
+}
@@ -0,0 +1,405 @@
+use crate::atomic_seq_num::AtomicSeqNum;
+use core::ops::Range;
+use core::sync::atomic::Ordering;
+
+/// A number generator like [`Sequence`](crate::Sequence) that, instead of requiring `&mut self`,
+/// can be shared across threads through a plain `&self` (e.g. behind an `Arc`) without a `Mutex`.
+///
+/// Produces monotonously increasing integer numbers, starting from a configurable start-point.
+///
+/// Passivates itself when the limit of the chosen type `T` is reached. Passive instances do not
+/// produce values anymore.
+///
+/// Works with all unsigned integers that have a native atomic counterpart, i.e. `u8` to `u64`
+/// and `usize`; `u128` is not supported because `std::sync::atomic` has no atomic type for it.
+///
+/// ## Example:
+///
+/// ```rust
+/// use sequential::AtomicSequence;
+/// use std::sync::Arc;
+///
+/// let sequence = Arc::new(AtomicSequence::<u32>::new());
+/// assert_eq!(sequence.next(), Some(0_u32));
+/// assert_eq!(sequence.next(), Some(1_u32));
+///
+/// sequence.continue_after(5);
+/// assert_eq!(sequence.next(), Some(6));
+/// ```
+pub struct AtomicSequence<T>
+where
+    T: AtomicSeqNum,
+{
+    next: T::Atomic,
+    // if > 0: the increment; if == 0: the instance is passivated
+    incr: T,
+    max: T,
+}
+
+impl<T> AtomicSequence<T>
+where
+    T: AtomicSeqNum,
+{
+    /// Produces an instance that starts with 0 and increments by 1.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            next: T::new_atomic(T::zero()),
+            incr: T::one(),
+            max: T::max_val(),
+        }
+    }
+
+    // Produces a dead instance, good for nothing.
+    #[must_use]
+    fn dead() -> Self {
+        Self {
+            next: T::new_atomic(T::zero()),
+            incr: T::zero(),
+            max: T::max_val(),
+        }
+    }
+
+    /// Produces an instance that starts with `val` and increments by 1.
+    #[must_use]
+    pub fn start_with(val: T) -> Self {
+        Self {
+            next: T::new_atomic(val),
+            incr: T::one(),
+            max: T::max_val(),
+        }
+    }
+
+    /// Produces an instance that starts with `val + 1` and increments by 1.
+    #[must_use]
+    pub fn start_after(val: T) -> Self {
+        match val.checked_add(T::one()) {
+            Some(next) => Self {
+                next: T::new_atomic(next),
+                incr: T::one(),
+                max: T::max_val(),
+            },
+            None => Self::dead(),
+        }
+    }
+
+    /// Produces an instance that starts after the highest value returned by the iterator.
+    pub fn start_after_highest(values: &mut dyn Iterator<Item = T>) -> Self {
+        Self::start_after(
+            values
+                .reduce(|x, y| core::cmp::max(x, y))
+                .unwrap_or(T::zero()),
+        )
+    }
+
+    /// Produces an instance with explicitly configured upper limit.
+    pub fn with_start_end_increment(start: T, end: T, incr: T) -> Self {
+        Self {
+            next: T::new_atomic(start),
+            incr,
+            max: end,
+        }
+    }
+
+    /// Consumes the `AtomicSequence` and produces one that increments with the given value.
+    ///
+    /// An increment of `0` produces a dead sequence that will not return any value.
+    ///
+    /// Note: the new increment takes effect _after_ the next value, not with the next value.
+    /// This is irrelevant if you call this method before consuming any value.
+    #[must_use]
+    pub fn with_increment(mut self, incr: T) -> Self {
+        if self.is_active() {
+            self.incr = incr;
+        }
+        self
+    }
+
+    /// Produces the next value, or `None` if the sequence is passivated.
+    ///
+    /// Implemented as a compare-and-swap retry loop, so it is safe to call concurrently from
+    /// many threads through a shared `&self`. Every `compare_exchange_weak` in this module uses
+    /// `AcqRel` on success, so each successful CAS acquires what the previous successful CAS
+    /// released, and `Relaxed` on failure and for the initial load, since a stale read
+    /// carries no information worth acting on beyond retrying with the freshly observed value.
+    ///
+    /// Note: unlike [`Sequence`](crate::Sequence), which is allowed a final `&mut self` write
+    /// to passivate itself, this never mutates `incr`, so in the extreme case where the next
+    /// value would overflow `T` itself (not just the configured `max`), that last value is
+    /// dropped and `None` is returned right away instead of being produced once more.
+    pub fn next(&self) -> Option<T> {
+        if self.is_passive() {
+            return None;
+        }
+
+        let mut current = T::load(&self.next, Ordering::Relaxed);
+        loop {
+            if current > self.max {
+                return None;
+            }
+
+            let Some(candidate) = current.checked_add(self.incr) else {
+                // `next` stays at `current` (this CAS writes the same value back), so every
+                // future call overflows the same way and also returns `None`.
+                let _ = T::compare_exchange_weak(
+                    &self.next,
+                    current,
+                    current,
+                    Ordering::AcqRel,
+                    Ordering::Relaxed,
+                );
+                return None;
+            };
+
+            match T::compare_exchange_weak(
+                &self.next,
+                current,
+                candidate,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Some(current),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Reserves `n` consecutive values in one step and returns them as a `Range`,
+    /// instead of calling [`AtomicSequence::next`] `n` times.
+    ///
+    /// Like [`AtomicSequence::next`], this is a compare-and-swap retry loop and is safe to
+    /// call concurrently from many threads through a shared `&self`.
+    ///
+    /// If the sequence is passivated, or if computing the reservation would overflow `T`
+    /// or exceed the configured upper limit, nothing is reserved: the sequence is left
+    /// unchanged and `None` is returned, so no values are silently lost or duplicated.
+    pub fn reserve(&self, n: T) -> Option<Range<T>> {
+        if self.is_passive() {
+            return None;
+        }
+        if n == T::zero() {
+            let current = T::load(&self.next, Ordering::Relaxed);
+            return if current > self.max || current.checked_add(self.incr).is_none() {
+                None
+            } else {
+                Some(current..current)
+            };
+        }
+
+        let span = self.incr.checked_mul(n)?;
+
+        let mut current = T::load(&self.next, Ordering::Relaxed);
+        loop {
+            let next = current.checked_add(span)?;
+            let last = next.checked_sub(self.incr)?;
+            if last > self.max {
+                return None;
+            }
+
+            match T::compare_exchange_weak(
+                &self.next,
+                current,
+                next,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Some(current..next),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Make sure that the sequence will never produce the given value,
+    /// by increasing the next value if necessary.
+    pub fn continue_after(&self, val: T) {
+        match val.checked_add(self.incr) {
+            Some(candidate) => self.advance_to(candidate),
+            None => self.advance_to(T::max_val()),
+        }
+    }
+
+    fn advance_to(&self, target: T) {
+        let mut current = T::load(&self.next, Ordering::Relaxed);
+        while current < target {
+            match T::compare_exchange_weak(
+                &self.next,
+                current,
+                target,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.incr != T::zero()
+    }
+    fn is_passive(&self) -> bool {
+        self.incr == T::zero()
+    }
+}
+
+impl<T> Default for AtomicSequence<T>
+where
+    T: AtomicSeqNum,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::fmt::Debug for AtomicSequence<T>
+where
+    T: AtomicSeqNum + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AtomicSequence")
+            .field("next", &T::load(&self.next, Ordering::Relaxed))
+            .field("incr", &self.incr)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AtomicSequence;
+    #[cfg(feature = "std")]
+    use std::sync::Arc;
+    #[cfg(feature = "std")]
+    use std::thread;
+
+    #[test]
+    fn test_sequence() {
+        let sequence = AtomicSequence::<usize>::new();
+        assert_eq!(sequence.next(), Some(0_usize));
+        assert_eq!(sequence.next(), Some(1_usize));
+
+        sequence.continue_after(5);
+        assert_eq!(sequence.next(), Some(6));
+
+        sequence.continue_after(15);
+        sequence.continue_after(7);
+        sequence.continue_after(0);
+        assert_eq!(sequence.next(), Some(16));
+    }
+
+    #[test]
+    fn test_increment() {
+        let sequence = AtomicSequence::<u8>::new().with_increment(5);
+        assert_eq!(sequence.next(), Some(0));
+        assert_eq!(sequence.next(), Some(5));
+        assert_eq!(sequence.next(), Some(10));
+
+        sequence.continue_after(152);
+        assert_eq!(sequence.next(), Some(157));
+        assert_eq!(sequence.next(), Some(162));
+
+        sequence.continue_after(251);
+        assert_eq!(sequence.next(), None);
+    }
+
+    #[test]
+    fn test_exhaust() {
+        // unlike `Sequence`, `AtomicSequence` never mutates `incr`, so it gives up the very
+        // last value once producing it would overflow `T` itself, rather than handing it out
+        // once more before passivating
+        let sequence = AtomicSequence::<u64>::new();
+        sequence.continue_after(u64::MAX - 1);
+        assert!(sequence.next().is_none());
+    }
+
+    #[test]
+    fn test_reserve() {
+        let sequence = AtomicSequence::<u32>::new();
+        assert_eq!(sequence.reserve(5), Some(0..5));
+        assert_eq!(sequence.next(), Some(5));
+
+        let sequence = AtomicSequence::<u8>::new().with_increment(5);
+        assert_eq!(sequence.reserve(3), Some(0..15));
+        assert_eq!(sequence.next(), Some(15));
+
+        // leaves the sequence unchanged when the reservation would exceed `max`
+        let sequence = AtomicSequence::<u8>::with_start_end_increment(250, 255, 1);
+        assert_eq!(sequence.reserve(10), None);
+        assert_eq!(sequence.next(), Some(250));
+
+        // reserving 0 values still reports exhaustion once `next` is beyond `max`
+        let sequence = AtomicSequence::<u8>::with_start_end_increment(10, 9, 1);
+        assert_eq!(sequence.reserve(0), None);
+    }
+
+    #[test]
+    fn test_reserve_zero_after_overflow() {
+        // `next()` has already returned `None` by overflowing `u8` itself (not just `max`),
+        // so a zero-length reservation must also report exhaustion, not a stale empty range
+        let sequence = AtomicSequence::<u8>::new();
+        sequence.continue_after(254);
+        assert_eq!(sequence.next(), None);
+        assert_eq!(sequence.reserve(0), None);
+    }
+
+    #[test]
+    fn test_reserve_after_continue_after() {
+        // a reservation must never reuse a value that `continue_after` skipped
+        let sequence = AtomicSequence::<u32>::new();
+        sequence.continue_after(9);
+        assert_eq!(sequence.reserve(5), Some(10..15));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_concurrent_reserve_uniqueness() {
+        let sequence = Arc::new(AtomicSequence::<u32>::new());
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let sequence = Arc::clone(&sequence);
+            handles.push(thread::spawn(move || {
+                let mut ranges = Vec::new();
+                for _ in 0..200 {
+                    ranges.push(sequence.reserve(5).unwrap());
+                }
+                ranges
+            }));
+        }
+
+        let mut all_values: Vec<u32> = handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .flatten()
+            .collect();
+        all_values.sort_unstable();
+        all_values.dedup();
+        assert_eq!(all_values.len(), 8000);
+        assert_eq!(all_values, (0..8000).collect::<Vec<u32>>());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_concurrent_uniqueness() {
+        let sequence = Arc::new(AtomicSequence::<u32>::new());
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let sequence = Arc::clone(&sequence);
+            handles.push(thread::spawn(move || {
+                let mut values = Vec::new();
+                for _ in 0..1000 {
+                    values.push(sequence.next().unwrap());
+                }
+                values
+            }));
+        }
+
+        let mut all_values: Vec<u32> = handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect();
+        all_values.sort_unstable();
+        all_values.dedup();
+        assert_eq!(all_values.len(), 8000);
+        assert_eq!(all_values, (0..8000).collect::<Vec<u32>>());
+    }
+}
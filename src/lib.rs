@@ -1,13 +1,20 @@
-//! Tiny library that provides with [`Sequence`] a configurable number generator.
+//! Tiny library that provides with [`Sequence`] a configurable number generator,
+//! and with [`AtomicSequence`] a lock-free variant that can be shared across threads.
+//!
+//! `no_std` compatible when the default `std` feature is switched off.
 
 // only enables the `doc_cfg` feature when the `docsrs` configuration attribute is defined
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 #![deny(clippy::all)]
 #![deny(clippy::pedantic)]
 #![forbid(unsafe_code)]
 
+mod atomic_seq_num;
+mod atomic_sequence;
 mod seq_num;
 mod sequence;
 
+pub use atomic_sequence::AtomicSequence;
 pub use sequence::Sequence;
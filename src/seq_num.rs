@@ -4,6 +4,10 @@ pub trait SeqNum: Copy + Ord + PartialEq {
     fn max_val() -> Self;
     /// Calls `checked_add` of the type.
     fn checked_add(self, other: Self) -> Option<Self>;
+    /// Calls `checked_sub` of the type.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// Calls `checked_mul` of the type.
+    fn checked_mul(self, other: Self) -> Option<Self>;
     /// Returns 0.
     fn zero() -> Self;
     /// Returns 1.
@@ -16,6 +20,12 @@ impl SeqNum for usize {
     fn checked_add(self, other: Self) -> Option<Self> {
         usize::checked_add(self, other)
     }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        usize::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        usize::checked_mul(self, other)
+    }
     fn zero() -> Self {
         0
     }
@@ -30,6 +40,12 @@ impl SeqNum for u8 {
     fn checked_add(self, other: Self) -> Option<Self> {
         u8::checked_add(self, other)
     }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u8::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u8::checked_mul(self, other)
+    }
     fn zero() -> Self {
         0
     }
@@ -44,6 +60,12 @@ impl SeqNum for u16 {
     fn checked_add(self, other: Self) -> Option<Self> {
         u16::checked_add(self, other)
     }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u16::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u16::checked_mul(self, other)
+    }
     fn zero() -> Self {
         0
     }
@@ -58,6 +80,12 @@ impl SeqNum for u32 {
     fn checked_add(self, other: Self) -> Option<Self> {
         u32::checked_add(self, other)
     }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u32::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u32::checked_mul(self, other)
+    }
     fn zero() -> Self {
         0
     }
@@ -72,6 +100,12 @@ impl SeqNum for u64 {
     fn checked_add(self, other: Self) -> Option<Self> {
         u64::checked_add(self, other)
     }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u64::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u64::checked_mul(self, other)
+    }
     fn zero() -> Self {
         0
     }
@@ -86,6 +120,12 @@ impl SeqNum for u128 {
     fn checked_add(self, other: Self) -> Option<Self> {
         u128::checked_add(self, other)
     }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u128::checked_sub(self, other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u128::checked_mul(self, other)
+    }
     fn zero() -> Self {
         0
     }
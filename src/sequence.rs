@@ -1,5 +1,6 @@
 use crate::seq_num::SeqNum;
-use std::iter::Iterator;
+use core::iter::Iterator;
+use core::ops::Range;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -105,7 +106,7 @@ where
     pub fn start_after_highest(values: &mut dyn Iterator<Item = T>) -> Self {
         Self::start_after(
             values
-                .reduce(|x, y| std::cmp::max(x, y))
+                .reduce(|x, y| core::cmp::max(x, y))
                 .unwrap_or(T::zero()),
         )
     }
@@ -148,7 +149,7 @@ where
     pub fn continue_after(&mut self, val: T) {
         match val.checked_add(self.incr) {
             Some(candidate) => {
-                self.next = std::cmp::max(self.next, candidate);
+                self.next = core::cmp::max(self.next, candidate);
             }
             None => {
                 self.set_passive();
@@ -156,6 +157,48 @@ where
         }
     }
 
+    /// Reserves `n` consecutive values in one step and returns them as a `Range`,
+    /// instead of calling [`Sequence::next`] `n` times.
+    ///
+    /// The stride between the returned values follows the configured increment
+    /// (see [`Sequence::with_increment`]).
+    ///
+    /// If the Sequence is passivated, or if computing the reservation would overflow `T`
+    /// or exceed the configured upper limit, nothing is reserved: the Sequence is left
+    /// unchanged and `None` is returned, so no values are silently lost or duplicated.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use sequential::Sequence;
+    /// let mut sequence = Sequence::<u32>::new();
+    /// assert_eq!(sequence.reserve(5), Some(0..5));
+    /// assert_eq!(sequence.next(), Some(5));
+    /// ```
+    pub fn reserve(&mut self, n: T) -> Option<Range<T>> {
+        if self.is_passive() {
+            return None;
+        }
+        if n == T::zero() {
+            return if self.next > self.max {
+                None
+            } else {
+                Some(self.next..self.next)
+            };
+        }
+
+        let span = self.incr.checked_mul(n)?;
+        let next = self.next.checked_add(span)?;
+        let last = next.checked_sub(self.incr)?;
+        if last > self.max {
+            return None;
+        }
+
+        let start = self.next;
+        self.next = next;
+        Some(start..next)
+    }
+
     fn set_passive(&mut self) {
         self.incr = T::zero();
     }
@@ -246,6 +289,38 @@ mod test {
         assert!(sequence.next().is_none());
     }
 
+    #[test]
+    fn test_reserve() {
+        let mut sequence = Sequence::<u32>::new();
+        assert_eq!(sequence.reserve(5), Some(0..5));
+        assert_eq!(sequence.next(), Some(5));
+
+        let mut sequence = Sequence::<u8>::new().with_increment(5);
+        assert_eq!(sequence.reserve(3), Some(0..15));
+        assert_eq!(sequence.next(), Some(15));
+
+        // leaves the sequence unchanged when the reservation would exceed `max`
+        let mut sequence = Sequence::<u8>::with_start_end_increment(250, 255, 1);
+        assert_eq!(sequence.reserve(10), None);
+        assert_eq!(sequence.next(), Some(250));
+
+        // leaves a passivated sequence untouched
+        let mut sequence = Sequence::<u8>::new().with_increment(0);
+        assert_eq!(sequence.reserve(3), None);
+
+        // reserving 0 values still reports exhaustion once `next` is beyond `max`
+        let mut sequence = Sequence::<u8>::with_start_end_increment(10, 9, 1);
+        assert_eq!(sequence.reserve(0), None);
+    }
+
+    #[test]
+    fn test_reserve_after_continue_after() {
+        // a reservation must never reuse a value that `continue_after` skipped
+        let mut sequence = Sequence::<u32>::new();
+        sequence.continue_after(9);
+        assert_eq!(sequence.reserve(5), Some(10..15));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde() {
@@ -254,7 +329,7 @@ mod test {
         let s = serde_json::to_string(&sequence).unwrap();
         assert_eq!(&*s, r#"{"next":33,"incr":11,"max":99}"#);
 
-        let mut sequence2: Sequence<u32> = serde_json::from_str(&*s).unwrap();
+        let mut sequence2: Sequence<u32> = serde_json::from_str(&s).unwrap();
         assert_eq!(sequence2.next(), Some(33));
         assert_eq!(sequence2.next(), Some(44));
         assert_eq!(sequence2.next(), Some(55));
@@ -266,7 +341,7 @@ mod test {
 
         // compatibility to old serialization format (without max)
         let old_format = r#"{"next":88,"incr":11}"#;
-        let mut sequence3: Sequence<u32> = serde_json::from_str(&old_format).unwrap();
+        let mut sequence3: Sequence<u32> = serde_json::from_str(old_format).unwrap();
         assert_eq!(sequence3.next(), Some(88));
         assert_eq!(sequence3.next(), Some(99));
         assert_eq!(sequence3.next(), Some(110));